@@ -1,5 +1,47 @@
-use std::sync::mpsc::{channel, Receiver};
-use threadpool::{Builder, ThreadPool};
+use std::any::Any;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::{self, ThreadId};
+use threadpool::Builder;
+
+/// An executor that can run fire-and-forget jobs, abstracting over
+/// [`threadpool::ThreadPool`] so adapters can also be driven by a
+/// caller-supplied pool shared across several parallel operations.
+pub trait ThreadPool: Clone {
+    /// Whatever the pool hands back for a spawned job; `threadpool::ThreadPool`
+    /// has nothing to report, but a custom executor may want to return a
+    /// cancellation or join handle here.
+    type JobHandle;
+
+    /// Runs `job` on one of the pool's worker threads.
+    fn spawn(&self, job: impl FnOnce() + Send + 'static) -> Self::JobHandle;
+
+    /// The number of workers the pool will run concurrently.
+    fn max_count(&self) -> usize;
+}
+
+impl ThreadPool for threadpool::ThreadPool {
+    type JobHandle = ();
+
+    fn spawn(&self, job: impl FnOnce() + Send + 'static) -> Self::JobHandle {
+        self.execute(job);
+    }
+
+    fn max_count(&self) -> usize {
+        threadpool::ThreadPool::max_count(self)
+    }
+}
+
+/// The process-wide pool that `num_threads: None` routes through, so that
+/// chaining several parallel adapters shares one bounded set of worker
+/// threads instead of each adapter spinning up its own.
+fn default_thread_pool() -> threadpool::ThreadPool {
+    static DEFAULT: OnceLock<threadpool::ThreadPool> = OnceLock::new();
+    DEFAULT.get_or_init(|| Builder::new().build()).clone()
+}
 
 pub trait ThreadedMappable<F>
 where
@@ -26,23 +68,465 @@ where
     /// assert_eq!(result, target);
     /// ```
     fn parallel_map(self, f: F, num_threads: Option<usize>) -> Self::Iter;
+
+    /// Like [`parallel_map`](Self::parallel_map), but runs on a caller-supplied
+    /// pool instead of one owned by the adapter, so it can be composed with
+    /// other parallel adapters over the same bounded set of worker threads.
+    fn parallel_map_on<Pool>(
+        self,
+        f: F,
+        pool: &Pool,
+    ) -> ThreadedMap<Self, F, <Self::Iter as Iterator>::Item, Pool>
+    where
+        Self: Sized,
+        Pool: ThreadPool,
+        <Self::Iter as Iterator>::Item: Sync,
+    {
+        ThreadedMap::on(self, f, pool)
+    }
+
+    /// Like [`parallel_map`](Self::parallel_map), but yields results as soon
+    /// as a worker finishes instead of waiting for their turn in source
+    /// order. This drops the reorder buffer entirely, so it is strictly
+    /// cheaper than `parallel_map` and is the right default for commutative
+    /// downstream work.
+    /// # Examples
+    /// ```
+    /// use std::collections::HashSet;
+    /// use threaded_map::ThreadedMappable;
+    /// let items = vec![1, 2, 3, 4, 5, 6];
+    /// let target: HashSet<_> = items.iter().map(i32::to_string).collect();
+    ///
+    /// let result: HashSet<_> = items
+    ///     .into_iter()
+    ///     .parallel_map_unordered(|item| item.to_string(), None)
+    ///     .collect();
+    ///
+    /// assert_eq!(result, target);
+    /// ```
+    fn parallel_map_unordered(
+        self,
+        f: F,
+        num_threads: Option<usize>,
+    ) -> ThreadedMapUnordered<Self, F, <Self::Iter as Iterator>::Item>
+    where
+        Self: Sized,
+    {
+        ThreadedMapUnordered::new(self, f, num_threads)
+    }
+
+    /// Like [`parallel_map_unordered`](Self::parallel_map_unordered), but runs
+    /// on a caller-supplied pool instead of one owned by the adapter.
+    fn parallel_map_unordered_on<Pool>(
+        self,
+        f: F,
+        pool: &Pool,
+    ) -> ThreadedMapUnordered<Self, F, <Self::Iter as Iterator>::Item, Pool>
+    where
+        Self: Sized,
+        Pool: ThreadPool,
+    {
+        ThreadedMapUnordered::on(self, f, pool)
+    }
+}
+
+pub trait ThreadedFilterable<P>
+where
+    Self: Iterator,
+    P: FnOnce(&<Self as Iterator>::Item) -> bool + Send + Clone,
+    <Self as Iterator>::Item: Send + Sync,
+{
+    type Iter: Iterator<Item = <Self as Iterator>::Item>;
+
+    /// Filters items of an iterator in parallel while conserving their order
+    /// # Examples
+    /// ```
+    /// use threaded_map::ThreadedFilterable;
+    /// let items = vec![1, 2, 3, 4, 5, 6];
+    /// let target: Vec<_> = items.iter().copied().filter(|i| i % 2 == 0).collect();
+    ///
+    /// let result: Vec<_> = items
+    ///     .into_iter()
+    ///     .parallel_filter(|i| i % 2 == 0, None)
+    ///     .collect();
+    ///
+    /// assert_eq!(result, target);
+    /// ```
+    fn parallel_filter(self, predicate: P, num_threads: Option<usize>) -> Self::Iter;
+
+    /// Like [`parallel_filter`](Self::parallel_filter), but runs on a
+    /// caller-supplied pool instead of one owned by the adapter.
+    fn parallel_filter_on<Pool>(self, predicate: P, pool: &Pool) -> ThreadedFilter<Self, P, Pool>
+    where
+        Self: Sized + 'static,
+        Pool: ThreadPool + 'static,
+    {
+        ThreadedFilter::on(self, predicate, pool)
+    }
+}
+
+pub trait ThreadedFilterMappable<F, O>
+where
+    Self: Iterator,
+    F: FnOnce(<Self as Iterator>::Item) -> Option<O> + Send + Clone,
+    <Self as Iterator>::Item: Send,
+    O: Send + Sync,
+{
+    type Iter: Iterator<Item = O>;
+
+    /// Filters and maps items of an iterator in parallel while conserving their order
+    /// # Examples
+    /// ```
+    /// use threaded_map::ThreadedFilterMappable;
+    /// let items = vec!["1", "two", "3", "four", "5"];
+    /// let target: Vec<_> = items.iter().filter_map(|s| s.parse::<i32>().ok()).collect();
+    ///
+    /// let result: Vec<_> = items
+    ///     .into_iter()
+    ///     .parallel_filter_map(|s| s.parse::<i32>().ok(), None)
+    ///     .collect();
+    ///
+    /// assert_eq!(result, target);
+    /// ```
+    fn parallel_filter_map(self, f: F, num_threads: Option<usize>) -> Self::Iter;
+
+    /// Like [`parallel_filter_map`](Self::parallel_filter_map), but runs on a
+    /// caller-supplied pool instead of one owned by the adapter.
+    fn parallel_filter_map_on<Pool>(
+        self,
+        f: F,
+        pool: &Pool,
+    ) -> ThreadedFilterMap<Self, F, O, Pool>
+    where
+        Self: Sized,
+        Pool: ThreadPool,
+    {
+        ThreadedFilterMap::on(self, f, pool)
+    }
+}
+
+pub trait ThreadedMapInitable<INIT, F, T, O>
+where
+    Self: Iterator,
+    INIT: Fn() -> T + Send + Clone,
+    F: Fn(&mut T, <Self as Iterator>::Item) -> O + Send + Clone,
+    <Self as Iterator>::Item: Send,
+    T: Send,
+    O: Send + Sync,
+{
+    type Iter: Iterator<Item = O>;
+
+    /// Maps items of an iterator in parallel while conserving their order,
+    /// amortizing the cost of expensive per-worker setup (a reusable buffer,
+    /// an RNG, a connection) across every item a worker handles.
+    /// # Examples
+    /// ```
+    /// use threaded_map::ThreadedMapInitable;
+    /// let items = vec![1, 2, 3, 4, 5, 6];
+    /// let target: Vec<_> = items.iter().map(i32::to_string).collect();
+    ///
+    /// let result: Vec<_> = items
+    ///     .into_iter()
+    ///     .parallel_map_init(String::new, |buf, item| {
+    ///         buf.clear();
+    ///         buf.push_str(&item.to_string());
+    ///         buf.clone()
+    ///     }, None)
+    ///     .collect();
+    ///
+    /// assert_eq!(result, target);
+    /// ```
+    fn parallel_map_init(self, init: INIT, f: F, num_threads: Option<usize>) -> Self::Iter;
+
+    /// Like [`parallel_map_init`](Self::parallel_map_init), but runs on a
+    /// caller-supplied pool instead of one owned by the adapter.
+    fn parallel_map_init_on<Pool>(
+        self,
+        init: INIT,
+        f: F,
+        pool: &Pool,
+    ) -> ThreadedMapInit<Self, INIT, F, T, O, Pool>
+    where
+        Self: Sized,
+        INIT: 'static,
+        F: 'static,
+        <Self as Iterator>::Item: 'static,
+        T: 'static,
+        Pool: ThreadPool,
+    {
+        ThreadedMapInit::on(self, init, f, pool)
+    }
+}
+
+/// The payload of a panic that [`panic::catch_unwind`] intercepted while
+/// running a job. Wrapped rather than carried as a bare `Box<dyn Any + Send>`
+/// so that its [`Debug`](std::fmt::Debug) output is the opaque
+/// `PanicPayload(..)`.
+struct PanicPayload(Box<dyn Any + Send>);
+
+impl std::fmt::Debug for PanicPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PanicPayload(..)")
+    }
+}
+
+/// What running a job produced: its value, the payload of a panic caught on
+/// its behalf, or `Cancelled` if a `ThreadPool` impl dropped the job instead
+/// of running it. `Cancelled` only ever comes from [`CancelOnDrop`]; nothing
+/// running a job produces it directly.
+#[derive(Debug)]
+enum JobOutcome<M> {
+    Value(M),
+    Panicked(PanicPayload),
+    Cancelled,
+}
+
+/// Runs `job` on `item`, catching a panic instead of letting it take down
+/// the worker thread that's running it.
+fn run_caught<F, Item, M>(job: F, item: Item) -> JobOutcome<M>
+where
+    F: FnOnce(Item) -> M,
+{
+    match panic::catch_unwind(AssertUnwindSafe(|| job(item))) {
+        Ok(value) => JobOutcome::Value(value),
+        Err(payload) => JobOutcome::Panicked(PanicPayload(payload)),
+    }
+}
+
+/// Guarantees the channel receives exactly one message for a submitted job
+/// even if the pool drops the job without running it: a `ThreadPool` impl is
+/// trusted to eventually drop `spawn`'s closure, but not to actually run it,
+/// so the closure itself can't be relied on to always reach its own `send`.
+/// Dropping this without calling [`send`](Self::send) sends `cancelled`
+/// instead, which unblocks a consumer that would otherwise wait on `recv`
+/// forever for a message that will never come.
+struct CancelOnDrop<Msg: Send + 'static> {
+    tx: Sender<Msg>,
+    cancelled: Option<Msg>,
+}
+
+impl<Msg: Send + 'static> CancelOnDrop<Msg> {
+    fn new(tx: Sender<Msg>, cancelled: Msg) -> Self {
+        Self {
+            tx,
+            cancelled: Some(cancelled),
+        }
+    }
+
+    /// Sends `msg` and disarms the fallback, since the job actually ran.
+    fn send(mut self, msg: Msg) {
+        self.cancelled = None;
+        let _ = self.tx.send(msg);
+    }
+}
+
+impl<Msg: Send + 'static> Drop for CancelOnDrop<Msg> {
+    fn drop(&mut self) {
+        if let Some(msg) = self.cancelled.take() {
+            let _ = self.tx.send(msg);
+        }
+    }
+}
+
+/// An item tagged with its position in the source iterator, ordered by that
+/// position alone so it can be dropped into a reorder buffer.
+#[derive(Debug)]
+struct Indexed<T>(usize, T);
+
+impl<T> PartialEq for Indexed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for Indexed<T> {}
+
+impl<T> PartialOrd for Indexed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Indexed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+/// Shared plumbing for adapters that keep up to `thread_pool.max_count()`
+/// jobs in flight on a pool and hand their results back in the order the
+/// items were pulled from the source iterator, regardless of the order the
+/// jobs finish in.
+///
+/// `M` is whatever a single job produces; it is generic so that adapters
+/// which may drop items (`ThreadedFilter`, `ThreadedFilterMap`) can route
+/// `Option<_>` through the same reorder buffer as `ThreadedMap`'s plain `O`.
+/// `Pool` is generic so the same plumbing can own a dedicated pool or share
+/// a caller-supplied one.
+#[derive(Debug)]
+struct Pipeline<I, M, Pool>
+where
+    I: Iterator,
+    M: Send + 'static,
+    Pool: ThreadPool,
+{
+    iterator: I,
+    thread_pool: Pool,
+    // `None` once the source iterator is exhausted: we hold this clone only
+    // so `submit_next` can hand out fresh ones, and dropping it at that point
+    // lets the channel actually disconnect once every in-flight job's own
+    // clone is gone, instead of staying open for the adapter's whole
+    // lifetime regardless of whether the pool ever runs those jobs.
+    sender: Option<Sender<Indexed<JobOutcome<M>>>>,
+    receiver: Receiver<Indexed<JobOutcome<M>>>,
+    reorder_buffer: BinaryHeap<Reverse<Indexed<JobOutcome<M>>>>,
+    next_emit: usize,
+    next_submit: usize,
+}
+
+impl<I, M, Pool> Pipeline<I, M, Pool>
+where
+    I: Iterator,
+    <I as Iterator>::Item: Send + 'static,
+    M: Send + 'static,
+    Pool: ThreadPool,
+{
+    fn new<F>(iterator: I, thread_pool: Pool, job: &F) -> Self
+    where
+        F: FnOnce(I::Item) -> M + Send + Clone + 'static,
+    {
+        let (sender, receiver) = channel();
+        let in_flight = thread_pool.max_count();
+        let mut this = Self {
+            iterator,
+            thread_pool,
+            sender: Some(sender),
+            receiver,
+            reorder_buffer: BinaryHeap::new(),
+            next_emit: 0,
+            next_submit: 0,
+        };
+
+        for _ in 0..in_flight {
+            if !this.submit_next(job) {
+                break;
+            }
+        }
+
+        this
+    }
+
+    /// Pulls the next item off the source iterator, if any, and submits it to
+    /// the pool tagged with its position so it can be slotted back into
+    /// order. The job runs under `catch_unwind`, so a panic is captured and
+    /// delivered alongside the item's position rather than taking the
+    /// worker thread down.
+    fn submit_next<F>(&mut self, job: &F) -> bool
+    where
+        F: FnOnce(I::Item) -> M + Send + Clone + 'static,
+    {
+        if self.sender.is_none() {
+            return false;
+        }
+        let Some(item) = self.iterator.next() else {
+            // No more items will ever be submitted, so drop our own clone of
+            // the sender: once every in-flight job's clone is gone too, the
+            // channel actually disconnects instead of staying open on our
+            // behalf forever.
+            self.sender = None;
+            return false;
+        };
+
+        let index = self.next_submit;
+        self.next_submit += 1;
+        let tx = self
+            .sender
+            .as_ref()
+            .expect("sender is only cleared once the iterator is exhausted, after which this function returns before reaching here")
+            .clone();
+        let job = job.clone();
+        // Built here, outside the closure handed to `spawn`: if the pool
+        // drops that closure instead of calling it, `guard` drops with it
+        // and still delivers a message, instead of the message only ever
+        // being sent from code that might never run.
+        let guard = CancelOnDrop::new(tx, Indexed(index, JobOutcome::Cancelled));
+        self.thread_pool.spawn(move || {
+            let outcome = run_caught(job, item);
+            guard.send(Indexed(index, outcome));
+        });
+
+        true
+    }
+
+    /// Returns the next result in source order as `Err` instead of resuming
+    /// a caught panic, refilling the in-flight slot it frees up. Blocks on
+    /// `recv` only while the reorder buffer's smallest index isn't the one
+    /// we're waiting to emit next, and returns `None` instead of blocking
+    /// forever if a job comes back `Cancelled` — which happens when a
+    /// `ThreadPool` impl drops a job instead of running it — or if the
+    /// channel closes outright while results are still outstanding.
+    fn poll_ordered_fallible<F>(&mut self, job: &F) -> Option<Result<M, Box<dyn Any + Send>>>
+    where
+        F: FnOnce(I::Item) -> M + Send + Clone + 'static,
+    {
+        loop {
+            if let Some(Reverse(Indexed(index, _))) = self.reorder_buffer.peek() {
+                if *index == self.next_emit {
+                    let Reverse(Indexed(_, outcome)) = self.reorder_buffer.pop().unwrap();
+                    self.next_emit += 1;
+                    return match outcome {
+                        JobOutcome::Cancelled => None,
+                        JobOutcome::Value(value) => {
+                            self.submit_next(job);
+                            Some(Ok(value))
+                        }
+                        JobOutcome::Panicked(PanicPayload(payload)) => {
+                            self.submit_next(job);
+                            Some(Err(payload))
+                        }
+                    };
+                }
+            }
+
+            if self.next_emit == self.next_submit {
+                return None;
+            }
+
+            match self.receiver.recv() {
+                Ok(indexed) => self.reorder_buffer.push(Reverse(indexed)),
+                Err(_) => return None,
+            }
+        }
+    }
+
+    /// Returns the next result in source order, refilling the in-flight slot
+    /// it frees up, and resumes a caught panic on the caller rather than
+    /// reporting it as a value.
+    fn poll_ordered<F>(&mut self, job: &F) -> Option<M>
+    where
+        F: FnOnce(I::Item) -> M + Send + Clone + 'static,
+    {
+        match self.poll_ordered_fallible(job)? {
+            Ok(value) => Some(value),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
 }
 
 #[derive(Debug)]
-pub struct ThreadedMap<I, F, O>
+pub struct ThreadedMap<I, F, O, Pool = threadpool::ThreadPool>
 where
     I: Iterator,
     F: FnOnce(<I as Iterator>::Item) -> O + 'static,
     <I as Iterator>::Item: 'static,
     O: Send + 'static,
+    Pool: ThreadPool,
 {
-    iterator: I,
+    pipeline: Pipeline<I, O, Pool>,
     function: F,
-    thread_pool: ThreadPool,
-    window: Vec<O>,
 }
 
-impl<I, F, O> ThreadedMap<I, F, O>
+impl<I, F, O> ThreadedMap<I, F, O, threadpool::ThreadPool>
 where
     I: Iterator,
     F: FnOnce(<I as Iterator>::Item) -> O + Send + Clone,
@@ -50,70 +534,803 @@ where
     O: Send + Sync,
 {
     pub fn new(iterator: I, function: F, num_threads: Option<usize>) -> Self {
+        let pool = num_threads.map_or_else(default_thread_pool, threadpool::ThreadPool::new);
+        Self::on(iterator, function, &pool)
+    }
+}
+
+impl<I, F, O, Pool> ThreadedMap<I, F, O, Pool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + Send + Clone,
+    <I as Iterator>::Item: Send,
+    O: Send + Sync,
+    Pool: ThreadPool,
+{
+    /// Builds the adapter on a caller-supplied pool, cloning its handle so
+    /// the pool can keep being shared by other parallel adapters.
+    pub fn on(iterator: I, function: F, pool: &Pool) -> Self {
         Self {
-            iterator,
+            pipeline: Pipeline::new(iterator, pool.clone(), &function),
             function,
-            thread_pool: num_threads.map_or_else(|| Builder::new().build(), ThreadPool::new),
-            window: Vec::new(),
         }
     }
+}
+
+impl<I, F, O, Pool> Iterator for ThreadedMap<I, F, O, Pool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + Send + Clone,
+    <I as Iterator>::Item: Send,
+    O: Send + Sync,
+    Pool: ThreadPool,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pipeline.poll_ordered(&self.function)
+    }
+}
+
+impl<I, F, O> ThreadedMappable<F> for I
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + Clone + Send + 'static,
+    <I as Iterator>::Item: Send + 'static,
+    O: Send + Sync + 'static,
+{
+    type Iter = ThreadedMap<Self, F, O>;
+
+    fn parallel_map(self, f: F, num_threads: Option<usize>) -> Self::Iter {
+        ThreadedMap::new(self, f, num_threads)
+    }
+}
+
+/// Like [`Pipeline`], but drops the reorder buffer and index bookkeeping: it
+/// just keeps up to `thread_pool.max_count()` jobs in flight and hands back
+/// whichever one finishes first.
+#[derive(Debug)]
+struct UnorderedPipeline<I, M, Pool>
+where
+    I: Iterator,
+    M: Send + 'static,
+    Pool: ThreadPool,
+{
+    iterator: I,
+    thread_pool: Pool,
+    // See `Pipeline::sender`: cleared once the source iterator is exhausted
+    // so the channel can actually disconnect once every in-flight job's own
+    // clone is gone.
+    sender: Option<Sender<JobOutcome<M>>>,
+    receiver: Receiver<JobOutcome<M>>,
+    in_flight: usize,
+}
+
+impl<I, M, Pool> UnorderedPipeline<I, M, Pool>
+where
+    I: Iterator,
+    <I as Iterator>::Item: Send + 'static,
+    M: Send + 'static,
+    Pool: ThreadPool,
+{
+    fn new<F>(iterator: I, thread_pool: Pool, job: &F) -> Self
+    where
+        F: FnOnce(I::Item) -> M + Send + Clone + 'static,
+    {
+        let (sender, receiver) = channel();
+        let mut this = Self {
+            iterator,
+            thread_pool,
+            sender: Some(sender),
+            receiver,
+            in_flight: 0,
+        };
+
+        let capacity = this.thread_pool.max_count();
+        for _ in 0..capacity {
+            if !this.submit_next(job) {
+                break;
+            }
+        }
+
+        this
+    }
 
-    fn send_items(&mut self) -> Receiver<(usize, O)> {
-        let (tx, rx) = channel::<(usize, O)>();
+    /// Pulls the next item off the source iterator, if any, and submits it to
+    /// the pool, tracking that one more result is outstanding. The job runs
+    /// under `catch_unwind`, so a panic is captured rather than taking the
+    /// worker thread down.
+    fn submit_next<F>(&mut self, job: &F) -> bool
+    where
+        F: FnOnce(I::Item) -> M + Send + Clone + 'static,
+    {
+        if self.sender.is_none() {
+            return false;
+        }
+        let Some(item) = self.iterator.next() else {
+            // No more items will ever be submitted, so drop our own clone of
+            // the sender: once every in-flight job's clone is gone too, the
+            // channel actually disconnects instead of staying open on our
+            // behalf forever.
+            self.sender = None;
+            return false;
+        };
 
-        for (index, item) in (0..self.thread_pool.max_count())
-            .map_while(|_| self.iterator.next())
-            .enumerate()
-        {
-            let tx = tx.clone();
-            let f = self.function.clone();
-            self.thread_pool.execute(move || {
-                tx.send((index, (f)(item)))
-                    .expect("channel will be there waiting for the pool");
-            });
+        let tx = self
+            .sender
+            .as_ref()
+            .expect("sender is only cleared once the iterator is exhausted, after which this function returns before reaching here")
+            .clone();
+        let job = job.clone();
+        // Built here, outside the closure handed to `spawn`: if the pool
+        // drops that closure instead of calling it, `guard` drops with it
+        // and still delivers a message, instead of the message only ever
+        // being sent from code that might never run.
+        let guard = CancelOnDrop::new(tx, JobOutcome::Cancelled);
+        self.thread_pool.spawn(move || {
+            let outcome = run_caught(job, item);
+            guard.send(outcome);
+        });
+        self.in_flight += 1;
+
+        true
+    }
+
+    /// Returns the next result to finish, in whatever order that happens to
+    /// be, refilling the in-flight slot it frees up. Resumes a caught panic
+    /// on the caller, and returns `None` instead of blocking forever if a job
+    /// comes back `Cancelled` — which happens when a `ThreadPool` impl drops
+    /// a job instead of running it — or if the channel closes outright while
+    /// results are still outstanding.
+    fn poll_unordered<F>(&mut self, job: &F) -> Option<M>
+    where
+        F: FnOnce(I::Item) -> M + Send + Clone + 'static,
+    {
+        if self.in_flight == 0 {
+            return None;
         }
 
-        rx
+        let result = match self.receiver.recv() {
+            Ok(result) => result,
+            Err(_) => {
+                self.in_flight = 0;
+                return None;
+            }
+        };
+        self.in_flight -= 1;
+        self.submit_next(job);
+        let value = match result {
+            JobOutcome::Cancelled => return None,
+            JobOutcome::Value(value) => value,
+            JobOutcome::Panicked(PanicPayload(payload)) => panic::resume_unwind(payload),
+        };
+        Some(value)
+    }
+}
+
+#[derive(Debug)]
+pub struct ThreadedMapUnordered<I, F, O, Pool = threadpool::ThreadPool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + 'static,
+    <I as Iterator>::Item: 'static,
+    O: Send + 'static,
+    Pool: ThreadPool,
+{
+    pipeline: UnorderedPipeline<I, O, Pool>,
+    function: F,
+}
+
+impl<I, F, O> ThreadedMapUnordered<I, F, O, threadpool::ThreadPool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + Send + Clone,
+    <I as Iterator>::Item: Send,
+    O: Send,
+{
+    pub fn new(iterator: I, function: F, num_threads: Option<usize>) -> Self {
+        let pool = num_threads.map_or_else(default_thread_pool, threadpool::ThreadPool::new);
+        Self::on(iterator, function, &pool)
     }
 }
 
-impl<I, F, O> Iterator for ThreadedMap<I, F, O>
+impl<I, F, O, Pool> ThreadedMapUnordered<I, F, O, Pool>
 where
     I: Iterator,
     F: FnOnce(<I as Iterator>::Item) -> O + Send + Clone,
     <I as Iterator>::Item: Send,
+    O: Send,
+    Pool: ThreadPool,
+{
+    /// Builds the adapter on a caller-supplied pool, cloning its handle so
+    /// the pool can keep being shared by other parallel adapters.
+    pub fn on(iterator: I, function: F, pool: &Pool) -> Self {
+        Self {
+            pipeline: UnorderedPipeline::new(iterator, pool.clone(), &function),
+            function,
+        }
+    }
+}
+
+impl<I, F, O, Pool> Iterator for ThreadedMapUnordered<I, F, O, Pool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + Send + Clone,
+    <I as Iterator>::Item: Send,
+    O: Send,
+    Pool: ThreadPool,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pipeline.poll_unordered(&self.function)
+    }
+}
+
+#[derive(Debug)]
+pub struct ThreadedFilter<I, P, Pool = threadpool::ThreadPool>
+where
+    I: Iterator,
+    P: FnOnce(&<I as Iterator>::Item) -> bool + 'static,
+    <I as Iterator>::Item: Send + 'static,
+    Pool: ThreadPool,
+{
+    pipeline: Pipeline<I, Option<I::Item>, Pool>,
+    predicate: P,
+}
+
+impl<I, P> ThreadedFilter<I, P, threadpool::ThreadPool>
+where
+    I: Iterator + 'static,
+    P: FnOnce(&<I as Iterator>::Item) -> bool + Send + Clone + 'static,
+    <I as Iterator>::Item: Send + Sync + 'static,
+{
+    pub fn new(iterator: I, predicate: P, num_threads: Option<usize>) -> Self {
+        let pool = num_threads.map_or_else(default_thread_pool, threadpool::ThreadPool::new);
+        Self::on(iterator, predicate, &pool)
+    }
+}
+
+impl<I, P, Pool> ThreadedFilter<I, P, Pool>
+where
+    I: Iterator + 'static,
+    P: FnOnce(&<I as Iterator>::Item) -> bool + Send + Clone + 'static,
+    <I as Iterator>::Item: Send + Sync + 'static,
+    Pool: ThreadPool + 'static,
+{
+    /// Builds the adapter on a caller-supplied pool, cloning its handle so
+    /// the pool can keep being shared by other parallel adapters.
+    pub fn on(iterator: I, predicate: P, pool: &Pool) -> Self {
+        let job = Self::job(predicate.clone());
+        Self {
+            pipeline: Pipeline::new(iterator, pool.clone(), &job),
+            predicate,
+        }
+    }
+
+    /// Wraps a predicate into a job that yields `Some(item)` when the
+    /// predicate holds and `None` otherwise, so the reorder buffer can skip a
+    /// rejected item without losing its place in the index sequence.
+    fn job(predicate: P) -> impl FnOnce(I::Item) -> Option<I::Item> + Send + Clone {
+        move |item| predicate(&item).then_some(item)
+    }
+}
+
+impl<I, P, Pool> Iterator for ThreadedFilter<I, P, Pool>
+where
+    I: Iterator + 'static,
+    P: FnOnce(&<I as Iterator>::Item) -> bool + Send + Clone + 'static,
+    <I as Iterator>::Item: Send + Sync + 'static,
+    Pool: ThreadPool + 'static,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let job = Self::job(self.predicate.clone());
+            match self.pipeline.poll_ordered(&job)? {
+                Some(item) => return Some(item),
+                None => continue,
+            }
+        }
+    }
+}
+
+impl<I, P> ThreadedFilterable<P> for I
+where
+    I: Iterator + 'static,
+    P: FnOnce(&<I as Iterator>::Item) -> bool + Clone + Send + 'static,
+    <I as Iterator>::Item: Send + Sync + 'static,
+{
+    type Iter = ThreadedFilter<Self, P>;
+
+    fn parallel_filter(self, predicate: P, num_threads: Option<usize>) -> Self::Iter {
+        ThreadedFilter::new(self, predicate, num_threads)
+    }
+}
+
+#[derive(Debug)]
+pub struct ThreadedFilterMap<I, F, O, Pool = threadpool::ThreadPool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> Option<O> + 'static,
+    <I as Iterator>::Item: 'static,
+    O: Send + 'static,
+    Pool: ThreadPool,
+{
+    pipeline: Pipeline<I, Option<O>, Pool>,
+    function: F,
+}
+
+impl<I, F, O> ThreadedFilterMap<I, F, O, threadpool::ThreadPool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> Option<O> + Send + Clone,
+    <I as Iterator>::Item: Send,
+    O: Send + Sync,
+{
+    pub fn new(iterator: I, function: F, num_threads: Option<usize>) -> Self {
+        let pool = num_threads.map_or_else(default_thread_pool, threadpool::ThreadPool::new);
+        Self::on(iterator, function, &pool)
+    }
+}
+
+impl<I, F, O, Pool> ThreadedFilterMap<I, F, O, Pool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> Option<O> + Send + Clone,
+    <I as Iterator>::Item: Send,
+    O: Send + Sync,
+    Pool: ThreadPool,
+{
+    /// Builds the adapter on a caller-supplied pool, cloning its handle so
+    /// the pool can keep being shared by other parallel adapters.
+    pub fn on(iterator: I, function: F, pool: &Pool) -> Self {
+        Self {
+            pipeline: Pipeline::new(iterator, pool.clone(), &function),
+            function,
+        }
+    }
+}
+
+impl<I, F, O, Pool> Iterator for ThreadedFilterMap<I, F, O, Pool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> Option<O> + Send + Clone,
+    <I as Iterator>::Item: Send,
     O: Send + Sync,
+    Pool: ThreadPool,
 {
     type Item = O;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(item) = self.window.pop() {
-            return Some(item);
+        loop {
+            match self.pipeline.poll_ordered(&self.function)? {
+                Some(item) => return Some(item),
+                None => continue,
+            }
         }
+    }
+}
 
-        let rx = self.send_items();
-        let mut window: Vec<_> = rx.iter().collect();
+impl<I, F, O> ThreadedFilterMappable<F, O> for I
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> Option<O> + Clone + Send + 'static,
+    <I as Iterator>::Item: Send + 'static,
+    O: Send + Sync + 'static,
+{
+    type Iter = ThreadedFilterMap<Self, F, O>;
 
-        if window.is_empty() {
-            return None;
+    fn parallel_filter_map(self, f: F, num_threads: Option<usize>) -> Self::Iter {
+        ThreadedFilterMap::new(self, f, num_threads)
+    }
+}
+
+
+/// Builds a job that lazily initializes this worker's `T` on first use and
+/// reuses it for every subsequent item the same worker thread handles.
+/// `state` is keyed by `ThreadId` and owned by the call itself (not a
+/// process-wide thread-local), so unrelated `parallel_map_init` calls
+/// sharing a pool don't collide on the same worker thread, and nothing
+/// needs explicit cleanup when the call ends: the map is simply dropped
+/// along with the last handle to it. The lock is only held to take and put
+/// back a worker's own `T`; `function` itself runs outside it, so distinct
+/// workers run concurrently instead of serializing on a single shared state.
+fn map_init_job<INIT, F, T, Item, O>(
+    state: Arc<Mutex<HashMap<ThreadId, T>>>,
+    init: INIT,
+    function: F,
+) -> impl FnOnce(Item) -> O + Send + Clone
+where
+    INIT: Fn() -> T + Send + Clone + 'static,
+    F: Fn(&mut T, Item) -> O + Send + Clone + 'static,
+    T: Send + 'static,
+{
+    move |item: Item| {
+        let thread_id = thread::current().id();
+        let existing = state
+            .lock()
+            .expect("worker state mutex is never poisoned by a panicking job")
+            .remove(&thread_id);
+        let mut t = existing.unwrap_or_else(&init);
+        let result = function(&mut t, item);
+        state
+            .lock()
+            .expect("worker state mutex is never poisoned by a panicking job")
+            .insert(thread_id, t);
+        result
+    }
+}
+
+#[derive(Debug)]
+pub struct ThreadedMapInit<I, INIT, F, T, O, Pool = threadpool::ThreadPool>
+where
+    I: Iterator,
+    INIT: Fn() -> T + 'static,
+    F: Fn(&mut T, <I as Iterator>::Item) -> O + 'static,
+    <I as Iterator>::Item: 'static,
+    T: 'static,
+    O: Send + 'static,
+    Pool: ThreadPool,
+{
+    pipeline: Pipeline<I, O, Pool>,
+    state: Arc<Mutex<HashMap<ThreadId, T>>>,
+    init: INIT,
+    function: F,
+}
+
+impl<I, INIT, F, T, O> ThreadedMapInit<I, INIT, F, T, O, threadpool::ThreadPool>
+where
+    I: Iterator,
+    INIT: Fn() -> T + Send + Clone + 'static,
+    F: Fn(&mut T, <I as Iterator>::Item) -> O + Send + Clone + 'static,
+    <I as Iterator>::Item: Send,
+    T: Send + 'static,
+    O: Send + Sync,
+{
+    pub fn new(iterator: I, init: INIT, function: F, num_threads: Option<usize>) -> Self {
+        let pool = num_threads.map_or_else(default_thread_pool, threadpool::ThreadPool::new);
+        Self::on(iterator, init, function, &pool)
+    }
+}
+
+impl<I, INIT, F, T, O, Pool> ThreadedMapInit<I, INIT, F, T, O, Pool>
+where
+    I: Iterator,
+    INIT: Fn() -> T + Send + Clone + 'static,
+    F: Fn(&mut T, <I as Iterator>::Item) -> O + Send + Clone + 'static,
+    <I as Iterator>::Item: Send,
+    T: Send + 'static,
+    O: Send + Sync,
+    Pool: ThreadPool,
+{
+    /// Builds the adapter on a caller-supplied pool, cloning its handle so
+    /// the pool can keep being shared by other parallel adapters.
+    pub fn on(iterator: I, init: INIT, function: F, pool: &Pool) -> Self {
+        let state = Arc::new(Mutex::new(HashMap::new()));
+        let job = map_init_job(Arc::clone(&state), init.clone(), function.clone());
+        Self {
+            pipeline: Pipeline::new(iterator, pool.clone(), &job),
+            state,
+            init,
+            function,
         }
+    }
+}
 
-        window.sort_by(|(lhs, _), (rhs, _)| rhs.cmp(lhs));
-        self.window = window.into_iter().map(|(_, item)| item).collect();
-        self.window.pop()
+impl<I, INIT, F, T, O, Pool> Iterator for ThreadedMapInit<I, INIT, F, T, O, Pool>
+where
+    I: Iterator,
+    INIT: Fn() -> T + Send + Clone + 'static,
+    F: Fn(&mut T, <I as Iterator>::Item) -> O + Send + Clone + 'static,
+    <I as Iterator>::Item: Send,
+    T: Send + 'static,
+    O: Send + Sync,
+    Pool: ThreadPool,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let job = map_init_job(Arc::clone(&self.state), self.init.clone(), self.function.clone());
+        self.pipeline.poll_ordered(&job)
     }
 }
 
-impl<I, F, O> ThreadedMappable<F> for I
+impl<I, INIT, F, T, O> ThreadedMapInitable<INIT, F, T, O> for I
+where
+    I: Iterator,
+    INIT: Fn() -> T + Send + Clone + 'static,
+    F: Fn(&mut T, <I as Iterator>::Item) -> O + Send + Clone + 'static,
+    <I as Iterator>::Item: Send + 'static,
+    T: Send + 'static,
+    O: Send + Sync + 'static,
+{
+    type Iter = ThreadedMapInit<Self, INIT, F, T, O>;
+
+    fn parallel_map_init(self, init: INIT, f: F, num_threads: Option<usize>) -> Self::Iter {
+        ThreadedMapInit::new(self, init, f, num_threads)
+    }
+}
+
+
+
+pub trait ThreadedForEachable<F>
+where
+    Self: Iterator,
+    F: Fn(<Self as Iterator>::Item) + Send + Clone,
+    <Self as Iterator>::Item: Send,
+{
+    /// Runs `f` over every item in parallel and blocks until all of them
+    /// have completed. There is no output to collect and no ordering
+    /// guarantee beyond "every item has been visited by the time this call
+    /// returns".
+    /// # Examples
+    /// ```
+    /// use std::sync::atomic::{AtomicI32, Ordering};
+    /// use std::sync::Arc;
+    /// use threaded_map::ThreadedForEachable;
+    /// let items = vec![1, 2, 3, 4, 5, 6];
+    /// let sum = Arc::new(AtomicI32::new(0));
+    /// let sum_clone = Arc::clone(&sum);
+    ///
+    /// items
+    ///     .into_iter()
+    ///     .parallel_for_each(move |item| { sum_clone.fetch_add(item, Ordering::Relaxed); }, None);
+    ///
+    /// assert_eq!(sum.load(Ordering::Relaxed), 21);
+    /// ```
+    fn parallel_for_each(self, f: F, num_threads: Option<usize>);
+
+    /// Like [`parallel_for_each`](Self::parallel_for_each), but runs on a
+    /// caller-supplied pool instead of one owned by the call.
+    fn parallel_for_each_on<Pool>(self, f: F, pool: &Pool)
+    where
+        Self: Sized,
+        Pool: ThreadPool;
+}
+
+impl<I, F> ThreadedForEachable<F> for I
+where
+    I: Iterator,
+    F: Fn(<I as Iterator>::Item) + Send + Clone + 'static,
+    <I as Iterator>::Item: Send + 'static,
+{
+    fn parallel_for_each(self, f: F, num_threads: Option<usize>) {
+        let pool = num_threads.map_or_else(default_thread_pool, threadpool::ThreadPool::new);
+        self.parallel_for_each_on(f, &pool);
+    }
+
+    fn parallel_for_each_on<Pool>(self, f: F, pool: &Pool)
+    where
+        Pool: ThreadPool,
+    {
+        let job = move |item| f(item);
+        let mut pipeline = UnorderedPipeline::new(self, pool.clone(), &job);
+        while pipeline.poll_unordered(&job).is_some() {}
+    }
+}
+
+pub trait ThreadedReducible<ID, C, M, T>
+where
+    Self: Iterator,
+    ID: Fn() -> T + Send + Clone,
+    C: Fn(T, <Self as Iterator>::Item) -> T + Send + Clone,
+    M: Fn(T, T) -> T + Send + Clone,
+    <Self as Iterator>::Item: Send,
+    T: Send,
+{
+    /// Reduces items of an iterator in parallel. Each worker thread seeds its
+    /// own accumulator from `identity` and folds its items into it with
+    /// `combine`, which is called outside any lock, so folding runs
+    /// concurrently across workers instead of being serialized behind one
+    /// shared accumulator; the worker-local
+    /// partials are then combined pairwise with `merge` into the final `T`
+    /// once every item has been processed. `identity` may run more than once
+    /// (once per worker that handles at least one item), so it should be
+    /// cheap and side-effect free, and `merge` should be associative for
+    /// deterministic results, since partials are merged in arrival order.
+    /// # Examples
+    /// ```
+    /// use threaded_map::ThreadedReducible;
+    /// let items = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// let sum = items
+    ///     .into_iter()
+    ///     .parallel_reduce(|| 0, |acc, item| acc + item, |a, b| a + b, None);
+    ///
+    /// assert_eq!(sum, 21);
+    /// ```
+    fn parallel_reduce(self, identity: ID, combine: C, merge: M, num_threads: Option<usize>) -> T;
+
+    /// Like [`parallel_reduce`](Self::parallel_reduce), but runs on a
+    /// caller-supplied pool instead of one owned by the call.
+    fn parallel_reduce_on<Pool>(self, identity: ID, combine: C, merge: M, pool: &Pool) -> T
+    where
+        Self: Sized,
+        Pool: ThreadPool;
+}
+
+impl<I, ID, C, M, T> ThreadedReducible<ID, C, M, T> for I
+where
+    I: Iterator,
+    ID: Fn() -> T + Send + Clone + 'static,
+    C: Fn(T, <I as Iterator>::Item) -> T + Send + Clone + 'static,
+    M: Fn(T, T) -> T + Send + Clone + 'static,
+    <I as Iterator>::Item: Send + 'static,
+    T: Send + 'static,
+{
+    fn parallel_reduce(self, identity: ID, combine: C, merge: M, num_threads: Option<usize>) -> T {
+        let pool = num_threads.map_or_else(default_thread_pool, threadpool::ThreadPool::new);
+        self.parallel_reduce_on(identity, combine, merge, &pool)
+    }
+
+    fn parallel_reduce_on<Pool>(self, identity: ID, combine: C, merge: M, pool: &Pool) -> T
+    where
+        Pool: ThreadPool,
+    {
+        // Keyed by the worker thread's `ThreadId` rather than a fixed slot
+        // per pool thread, since `ThreadPool` gives no way to enumerate or
+        // address its workers up front. The lock is only held to take and
+        // put back a worker's own partial; `combine` itself runs outside it,
+        // so distinct workers fold concurrently instead of serializing on a
+        // single shared accumulator. Each partial carries the arrival number
+        // of its worker's first item, so partials merge in arrival order.
+        let partials: Arc<Mutex<HashMap<ThreadId, (usize, T)>>> = Arc::new(Mutex::new(HashMap::new()));
+        let job = {
+            let partials = Arc::clone(&partials);
+            let arrivals = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let identity = identity.clone();
+            let combine = combine.clone();
+            move |item| {
+                let thread_id = thread::current().id();
+                let existing = partials
+                    .lock()
+                    .expect("partials mutex is never poisoned by a panicking job")
+                    .remove(&thread_id);
+                let (arrival, acc) = existing.unwrap_or_else(|| {
+                    (arrivals.fetch_add(1, std::sync::atomic::Ordering::Relaxed), identity())
+                });
+                let acc = combine(acc, item);
+                partials
+                    .lock()
+                    .expect("partials mutex is never poisoned by a panicking job")
+                    .insert(thread_id, (arrival, acc));
+            }
+        };
+        let mut pipeline = UnorderedPipeline::new(self, pool.clone(), &job);
+        while pipeline.poll_unordered(&job).is_some() {}
+        drop(job);
+
+        let mut partials: Vec<(usize, T)> = Arc::try_unwrap(partials)
+            .unwrap_or_else(|_| {
+                unreachable!("no pool worker can still hold the partials map once the pipeline is drained")
+            })
+            .into_inner()
+            .expect("partials mutex is never poisoned by a panicking job")
+            .into_values()
+            .collect();
+        partials.sort_unstable_by_key(|(arrival, _)| *arrival);
+
+        let mut partials = partials.into_iter().map(|(_, acc)| acc);
+        let Some(first) = partials.next() else {
+            return identity();
+        };
+        partials.fold(first, merge)
+    }
+}
+
+
+pub trait ThreadedTryMappable<F, O>
+where
+    Self: Iterator,
+    F: FnOnce(<Self as Iterator>::Item) -> O + Send + Clone,
+    <Self as Iterator>::Item: Send,
+    O: Send + Sync,
+{
+    type Iter: Iterator<Item = Result<O, Box<dyn Any + Send>>>;
+
+    /// Maps items of an iterator in parallel while conserving their order,
+    /// like [`ThreadedMappable::parallel_map`], but catches a panic from `f`
+    /// instead of letting it take a worker down with it: a panicking call
+    /// surfaces as `Err` at its item's position rather than hanging or
+    /// silently dropping the result.
+    /// # Examples
+    /// ```
+    /// use threaded_map::ThreadedTryMappable;
+    /// let items = vec![1, 2, 0, 4];
+    ///
+    /// let result: Vec<_> = items
+    ///     .into_iter()
+    ///     .try_parallel_map(|item| 10 / item, None)
+    ///     .map(|r| r.is_ok())
+    ///     .collect();
+    ///
+    /// assert_eq!(result, vec![true, true, false, true]);
+    /// ```
+    fn try_parallel_map(self, f: F, num_threads: Option<usize>) -> Self::Iter;
+
+    /// Like [`try_parallel_map`](Self::try_parallel_map), but runs on a
+    /// caller-supplied pool instead of one owned by the adapter.
+    fn try_parallel_map_on<Pool>(self, f: F, pool: &Pool) -> ThreadedTryMap<Self, F, O, Pool>
+    where
+        Self: Sized,
+        Pool: ThreadPool,
+    {
+        ThreadedTryMap::on(self, f, pool)
+    }
+}
+
+#[derive(Debug)]
+pub struct ThreadedTryMap<I, F, O, Pool = threadpool::ThreadPool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + 'static,
+    <I as Iterator>::Item: 'static,
+    O: Send + 'static,
+    Pool: ThreadPool,
+{
+    pipeline: Pipeline<I, O, Pool>,
+    function: F,
+}
+
+impl<I, F, O> ThreadedTryMap<I, F, O, threadpool::ThreadPool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + Send + Clone,
+    <I as Iterator>::Item: Send,
+    O: Send + Sync,
+{
+    pub fn new(iterator: I, function: F, num_threads: Option<usize>) -> Self {
+        let pool = num_threads.map_or_else(default_thread_pool, threadpool::ThreadPool::new);
+        Self::on(iterator, function, &pool)
+    }
+}
+
+impl<I, F, O, Pool> ThreadedTryMap<I, F, O, Pool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + Send + Clone,
+    <I as Iterator>::Item: Send,
+    O: Send + Sync,
+    Pool: ThreadPool,
+{
+    /// Builds the adapter on a caller-supplied pool, cloning its handle so
+    /// the pool can keep being shared by other parallel adapters.
+    pub fn on(iterator: I, function: F, pool: &Pool) -> Self {
+        Self {
+            pipeline: Pipeline::new(iterator, pool.clone(), &function),
+            function,
+        }
+    }
+}
+
+impl<I, F, O, Pool> Iterator for ThreadedTryMap<I, F, O, Pool>
+where
+    I: Iterator,
+    F: FnOnce(<I as Iterator>::Item) -> O + Send + Clone,
+    <I as Iterator>::Item: Send,
+    O: Send + Sync,
+    Pool: ThreadPool,
+{
+    type Item = Result<O, Box<dyn Any + Send>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pipeline.poll_ordered_fallible(&self.function)
+    }
+}
+
+impl<I, F, O> ThreadedTryMappable<F, O> for I
 where
     I: Iterator,
     F: FnOnce(<I as Iterator>::Item) -> O + Clone + Send + 'static,
     <I as Iterator>::Item: Send + 'static,
     O: Send + Sync + 'static,
 {
-    type Iter = ThreadedMap<Self, F, O>;
+    type Iter = ThreadedTryMap<Self, F, O>;
 
-    fn parallel_map(self, f: F, num_threads: Option<usize>) -> Self::Iter {
-        ThreadedMap::new(self, f, num_threads)
+    fn try_parallel_map(self, f: F, num_threads: Option<usize>) -> Self::Iter {
+        ThreadedTryMap::new(self, f, num_threads)
     }
 }
+